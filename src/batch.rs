@@ -0,0 +1,117 @@
+use error::Error;
+use government_id::{GovernmentId, GovernmentIdKind};
+
+/// The outcome of validating a single identifier: the type that was
+/// detected (if any), whether it is valid, and the specific error
+/// when it isn't.
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    pub input: String,
+    pub kind: Option<GovernmentIdKind>,
+    pub valid: bool,
+    pub error: Option<Error>,
+}
+
+/// Detects and validates a single identifier, never panicking or
+/// short-circuiting on failure. On failure, the length profile is
+/// used to report which type was being attempted (e.g. a 13-digit
+/// value with a bad check digit is still reported as `Ogrn`) unless
+/// the value never got that far (empty, or contains non-digits).
+fn validate_one(input: &str) -> ValidationReport {
+    match GovernmentId::detect(input) {
+        Ok(id) => ValidationReport {
+            input: input.into(),
+            kind: Some(id.kind()),
+            valid: true,
+            error: None,
+        },
+        Err(err) => {
+            let kind = match err {
+                Error::Empty | Error::ExpectedNumbersOnly => None,
+                _ => GovernmentId::kind_for_length(input),
+            };
+
+            ValidationReport {
+                input: input.into(),
+                kind,
+                valid: false,
+                error: Some(err),
+            }
+        }
+    }
+}
+
+/// Validates every identifier in `inputs`, collecting a report per
+/// item without stopping at the first failure.
+pub fn validate_all<'a, I>(inputs: I) -> Vec<ValidationReport>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    ValidateAll {
+        inputs: inputs.into_iter(),
+    }
+    .collect()
+}
+
+/// A streaming adapter over `validate_all`, for callers who want to
+/// process reports one at a time instead of collecting them all at
+/// once.
+pub struct ValidateAll<I> {
+    inputs: I,
+}
+
+impl<I> ValidateAll<I> {
+    pub fn new(inputs: I) -> Self {
+        ValidateAll { inputs }
+    }
+}
+
+impl<'a, I> Iterator for ValidateAll<I>
+where
+    I: Iterator<Item = &'a str>,
+{
+    type Item = ValidationReport;
+
+    fn next(&mut self) -> Option<ValidationReport> {
+        self.inputs.next().map(validate_one)
+    }
+}
+
+/// A summary over a batch of `ValidationReport`s: how many were
+/// valid, how many failed, and a breakdown of failures by error
+/// kind.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationSummary {
+    pub valid_count: usize,
+    pub invalid_count: usize,
+    pub errors: Vec<(Error, usize)>,
+}
+
+impl ValidationSummary {
+    /// Summarizes a set of reports, grouping invalid entries by their
+    /// `Error` variant.
+    pub fn summarize<'a, I>(reports: I) -> ValidationSummary
+    where
+        I: IntoIterator<Item = &'a ValidationReport>,
+    {
+        let mut summary = ValidationSummary::default();
+
+        for report in reports {
+            if report.valid {
+                summary.valid_count += 1;
+                continue;
+            }
+
+            summary.invalid_count += 1;
+
+            if let Some(ref err) = report.error {
+                match summary.errors.iter_mut().find(|(e, _)| e == err) {
+                    Some(entry) => entry.1 += 1,
+                    None => summary.errors.push((err.clone(), 1)),
+                }
+            }
+        }
+
+        summary
+    }
+}
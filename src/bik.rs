@@ -0,0 +1,106 @@
+use super::{ValidResult, Validate};
+use error::Error;
+
+/// This structure describes the bank identification code (БИК) and
+/// allows to obtain information about its properties. To check
+/// whether it is correct.
+///
+/// Every BIK issued by the Bank of Russia begins with `04`; that
+/// prefix is the only structural marker available, since BIK has no
+/// check digit of its own.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let bik: Bik = "044525225".to_owned().into();
+/// assert!(bik.is_valid().unwrap());
+///
+/// ```
+///
+pub struct Bik {
+    value: String,
+}
+
+impl Bik {
+    const LENGTH: usize = 9;
+    const PREFIX: &'static str = "04";
+
+    /// Creates a new `Bik`
+    pub fn new(input: &str) -> Self {
+        Bik {
+            value: input.into(),
+        }
+    }
+}
+
+impl Validate for Bik {
+    fn is_valid(&self) -> ValidResult {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Bik::LENGTH {
+            return Err(Error::WrongLength {
+                length: Bik::LENGTH,
+            });
+        }
+
+        Ok(self.value.starts_with(Bik::PREFIX))
+    }
+}
+
+impl From<String> for Bik {
+    fn from(other: String) -> Bik {
+        Bik { value: other }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error;
+    use super::*;
+
+    fn create_bik(s: &str) -> Bik {
+        Bik::new(s)
+    }
+
+    #[test]
+    fn test_empty_bik() {
+        assert!(match create_bik("").is_valid() {
+            Err(error::Error::Empty) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_valid_bik() {
+        assert!(create_bik("044525225").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_bik_without_prefix() {
+        assert!(create_bik("770701001").is_valid().unwrap() == false);
+    }
+
+    #[test]
+    fn test_invalid_bik_wrong_length() {
+        match create_bik("0445252").is_valid() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_invalid_bik_with_litters() {
+        match create_bik("04452522f").is_valid() {
+            Err(error::Error::ExpectedNumbersOnly) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+}
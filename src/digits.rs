@@ -0,0 +1,25 @@
+//! Small digit-parsing helpers shared by the fixed-width identifier
+//! types (`tax_id`, `ogrn`, `snils`).
+
+/// Gets number from string by index.
+pub fn get_digit(input: &str, n: usize) -> u32 {
+    match input.chars().nth(n) {
+        Some(ch) => match ch.to_digit(10) {
+            Some(d) => d,
+            None => 0,
+        },
+        None => 0,
+    }
+}
+
+/// Builds a multi-digit number from `len` consecutive digits of
+/// `input`, starting at index `start`.
+pub fn digits_to_u32(input: &str, start: usize, len: usize) -> u32 {
+    let mut acc = 0;
+
+    for i in start..start + len {
+        acc = acc * 10 + get_digit(input, i);
+    }
+
+    acc
+}
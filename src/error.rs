@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// The ways a government identifier can fail to validate, or a
+/// `Verifier` lookup can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input was empty.
+    Empty,
+    /// The input did not contain the expected number of digits.
+    WrongLength { length: usize },
+    /// The input contained characters other than digits.
+    ExpectedNumbersOnly,
+    /// The input had the right shape but its check digit(s) didn't
+    /// match.
+    InvalidChecksum,
+    /// No known identifier type matches the input's length.
+    UnrecognizedIdentifier,
+    /// An online verification request could not be sent or its
+    /// response could not be read.
+    NetworkFailure,
+    /// An online verification response could not be parsed.
+    ResponseParseFailure,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty => write!(f, "value is empty"),
+            Error::WrongLength { length } => write!(f, "expected {} digits", length),
+            Error::ExpectedNumbersOnly => write!(f, "expected numbers only"),
+            Error::InvalidChecksum => write!(f, "check digit does not match"),
+            Error::UnrecognizedIdentifier => write!(f, "value matches no known identifier"),
+            Error::NetworkFailure => write!(f, "verification request failed"),
+            Error::ResponseParseFailure => write!(f, "could not parse verification response"),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Empty => "value is empty",
+            Error::WrongLength { .. } => "wrong length",
+            Error::ExpectedNumbersOnly => "expected numbers only",
+            Error::InvalidChecksum => "invalid checksum",
+            Error::UnrecognizedIdentifier => "unrecognized identifier",
+            Error::NetworkFailure => "verification request failed",
+            Error::ResponseParseFailure => "could not parse verification response",
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use super::Validate;
+use bik::Bik;
+use error::Error;
+use kpp::Kpp;
+use ogrn::{Ogrn, Ogrnip};
+use snils::Snils;
+use tax_id::TaxpayerIdentificationNumber;
+
+/// A government identifier whose concrete type was detected from the
+/// shape of the input string, rather than chosen up front by the
+/// caller.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let id: GovernmentId = "7827004526".parse().unwrap();
+/// match id {
+///     GovernmentId::Inn(_) => (),
+///     _ => panic!("expected an INN"),
+/// }
+///
+/// ```
+///
+pub enum GovernmentId {
+    Inn(TaxpayerIdentificationNumber),
+    Kpp(Kpp),
+    Bik(Bik),
+    Snils(Snils),
+    Ogrn(Ogrn),
+    Ogrnip(Ogrnip),
+}
+
+/// Which concrete identifier type a `GovernmentId` holds, without
+/// borrowing the value itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GovernmentIdKind {
+    Inn,
+    Kpp,
+    Bik,
+    Snils,
+    Ogrn,
+    Ogrnip,
+}
+
+/// Tries to build and validate a `GovernmentId` variant from `value`.
+/// Returns the wrapped variant if the checksum passes, and otherwise
+/// the concrete `Error` explaining why it didn't — a format error
+/// from `is_valid()` itself, or `Error::InvalidChecksum` if the value
+/// was well-formed but the check digit didn't match.
+fn try_variant<T, F>(
+    value: &str,
+    new: F,
+    wrap: fn(T) -> GovernmentId,
+) -> Result<GovernmentId, Error>
+where
+    T: Validate,
+    F: Fn(&str) -> T,
+{
+    let id = new(value);
+    match id.is_valid() {
+        Ok(true) => Ok(wrap(id)),
+        Ok(false) => Err(Error::InvalidChecksum),
+        Err(err) => Err(err),
+    }
+}
+
+impl FromStr for GovernmentId {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        GovernmentId::detect(value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for GovernmentId {
+    type Error = Error;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        GovernmentId::detect(value)
+    }
+}
+
+impl GovernmentId {
+    /// Returns which concrete identifier type this value holds.
+    pub fn kind(&self) -> GovernmentIdKind {
+        match *self {
+            GovernmentId::Inn(_) => GovernmentIdKind::Inn,
+            GovernmentId::Kpp(_) => GovernmentIdKind::Kpp,
+            GovernmentId::Bik(_) => GovernmentIdKind::Bik,
+            GovernmentId::Snils(_) => GovernmentIdKind::Snils,
+            GovernmentId::Ogrn(_) => GovernmentIdKind::Ogrn,
+            GovernmentId::Ogrnip(_) => GovernmentIdKind::Ogrnip,
+        }
+    }
+
+    /// Detects which identifier `value` is by trying every candidate
+    /// type whose length profile fits, in order, and returning the
+    /// first one that also passes its checksum. If a length profile
+    /// matches but every candidate for it fails, the last candidate's
+    /// own `Error` is returned (so callers see e.g.
+    /// `Error::InvalidChecksum`, not a generic "unrecognized"
+    /// failure); `Error::UnrecognizedIdentifier` is reserved for
+    /// lengths that don't match any known identifier at all.
+    ///
+    /// NOTE: KPP and BIK share the 9-digit length profile, and KPP
+    /// has no check digit of its own — it accepts any 9-digit value,
+    /// so trying it first would make BIK unreachable. BIK does carry
+    /// a structural marker instead (every BIK issued by the Bank of
+    /// Russia starts with "04"), so that prefix is used to pick which
+    /// candidate to try first; see `GovernmentId::kind_for_length`.
+    pub fn detect(value: &str) -> Result<Self, Error> {
+        if value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        match value.len() {
+            9 => {
+                if value.starts_with(BIK_PREFIX) {
+                    try_variant(value, Bik::new, GovernmentId::Bik)
+                        .or_else(|_| try_variant(value, Kpp::new, GovernmentId::Kpp))
+                } else {
+                    try_variant(value, Kpp::new, GovernmentId::Kpp)
+                        .or_else(|_| try_variant(value, Bik::new, GovernmentId::Bik))
+                }
+            }
+            10 | 12 => try_variant(value, TaxpayerIdentificationNumber::new, GovernmentId::Inn),
+            11 => try_variant(value, Snils::new, GovernmentId::Snils),
+            13 => try_variant(value, Ogrn::new, GovernmentId::Ogrn),
+            15 => try_variant(value, Ogrnip::new, GovernmentId::Ogrnip),
+            _ => Err(Error::UnrecognizedIdentifier),
+        }
+    }
+
+    /// Returns which identifier type would be attempted for `value`,
+    /// without validating anything. Used for batch diagnostics so a
+    /// failed item can still be labeled with the type it was
+    /// attempted as. Mirrors the dispatch table in `detect`,
+    /// including the "04" prefix used to tell a 9-digit BIK from a
+    /// 9-digit KPP.
+    pub fn kind_for_length(value: &str) -> Option<GovernmentIdKind> {
+        match value.len() {
+            9 => Some(if value.starts_with(BIK_PREFIX) {
+                GovernmentIdKind::Bik
+            } else {
+                GovernmentIdKind::Kpp
+            }),
+            10 | 12 => Some(GovernmentIdKind::Inn),
+            11 => Some(GovernmentIdKind::Snils),
+            13 => Some(GovernmentIdKind::Ogrn),
+            15 => Some(GovernmentIdKind::Ogrnip),
+            _ => None,
+        }
+    }
+}
+
+/// Every BIK issued by the Bank of Russia starts with this prefix.
+const BIK_PREFIX: &str = "04";
@@ -0,0 +1,99 @@
+use super::{ValidResult, Validate};
+use error::Error;
+
+/// This structure describes the reason code for tax registration
+/// (КПП) and allows to obtain information about its properties. To
+/// check whether it is correct.
+///
+/// КПП has no check digit of its own, so validation is limited to
+/// the length/format of the value.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let kpp: Kpp = "770701001".to_owned().into();
+/// assert!(kpp.is_valid().unwrap());
+///
+/// ```
+///
+pub struct Kpp {
+    value: String,
+}
+
+impl Kpp {
+    const LENGTH: usize = 9;
+
+    /// Creates a new `Kpp`
+    pub fn new(input: &str) -> Self {
+        Kpp {
+            value: input.into(),
+        }
+    }
+}
+
+impl Validate for Kpp {
+    fn is_valid(&self) -> ValidResult {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Kpp::LENGTH {
+            return Err(Error::WrongLength {
+                length: Kpp::LENGTH,
+            });
+        }
+
+        Ok(true)
+    }
+}
+
+impl From<String> for Kpp {
+    fn from(other: String) -> Kpp {
+        Kpp { value: other }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error;
+    use super::*;
+
+    fn create_kpp(s: &str) -> Kpp {
+        Kpp::new(s)
+    }
+
+    #[test]
+    fn test_empty_kpp() {
+        assert!(match create_kpp("").is_valid() {
+            Err(error::Error::Empty) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_valid_kpp() {
+        assert!(create_kpp("770701001").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_kpp_wrong_length() {
+        match create_kpp("7707010").is_valid() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_invalid_kpp_with_litters() {
+        match create_kpp("77070100f").is_valid() {
+            Err(error::Error::ExpectedNumbersOnly) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+}
@@ -0,0 +1,39 @@
+//! Validation for Russian government identifiers: INN, KPP, BIK,
+//! SNILS, OGRN and OGRNIP.
+
+mod bik;
+mod digits;
+mod error;
+mod government_id;
+mod kpp;
+mod ogrn;
+mod snils;
+mod tax_id;
+
+pub mod batch;
+#[cfg(feature = "fns_online")]
+pub mod verify;
+
+pub use bik::Bik;
+pub use error::Error;
+pub use government_id::{GovernmentId, GovernmentIdKind};
+pub use kpp::Kpp;
+pub use ogrn::{Ogrn, OgrnInfo, Ogrnip};
+pub use snils::Snils;
+pub use tax_id::{TaxpayerIdentificationNumber, TaxpayerIdentificationNumberInfo};
+
+/// The result of checking whether a value is a correctly formed
+/// identifier.
+pub type ValidResult = Result<bool, Error>;
+
+/// Implemented by every government identifier type in this crate.
+pub trait Validate {
+    /// Checks whether the value is a correctly formed identifier,
+    /// including its checksum where the identifier type has one.
+    fn is_valid(&self) -> ValidResult;
+}
+
+/// Returns `true` if every character in `input` is an ASCII digit.
+fn only_digits(input: &str) -> bool {
+    input.chars().all(|c| c.is_digit(10))
+}
@@ -0,0 +1,280 @@
+use super::{ValidResult, Validate};
+use digits::{digits_to_u32, get_digit};
+use error::Error;
+
+/// This structure describes the primary state registration number
+/// (ОГРН) of a legal entity and allows to obtain information about
+/// its properties. To check whether it is correct.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let ogrn: Ogrn = "1027700132195".to_owned().into();
+/// assert!(ogrn.is_valid().unwrap());
+///
+/// ```
+///
+pub struct Ogrn {
+    value: String,
+}
+
+impl Ogrn {
+    const LENGTH: usize = 13;
+
+    /// Creates a new `Ogrn`
+    pub fn new(input: &str) -> Self {
+        Ogrn {
+            value: input.into(),
+        }
+    }
+
+    /// Returns the raw value this identifier was constructed from.
+    #[cfg(feature = "fns_online")]
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Calculates the check digit by taking the first 12 digits
+    /// as an integer modulo 11, and the last decimal digit of that
+    /// remainder.
+    fn check_digit(&self) -> u32 {
+        let mut acc: u64 = 0;
+
+        for i in 0..12 {
+            let digit = get_digit(&self.value, i) as u64;
+            acc = (acc * 10 + digit) % 11;
+        }
+
+        (acc % 10) as u32
+    }
+
+    /// Decodes the number into its structural subfields: the sign
+    /// of the registration record, the year of registration, the
+    /// federation-subject code, the tax-inspection code and the
+    /// record number. This is a structural decode only — see the
+    /// same note on `tax_id::TaxpayerIdentificationNumber::decode`.
+    pub fn decode(&self) -> Result<OgrnInfo, Error> {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Ogrn::LENGTH {
+            return Err(Error::WrongLength {
+                length: Ogrn::LENGTH,
+            });
+        }
+
+        Ok(OgrnInfo {
+            sign: get_digit(&self.value, 0),
+            year: digits_to_u32(&self.value, 1, 2),
+            region_code: digits_to_u32(&self.value, 3, 2),
+            inspection_code: digits_to_u32(&self.value, 5, 2),
+            record_number: digits_to_u32(&self.value, 7, 5),
+            check_digit: get_digit(&self.value, 12),
+        })
+    }
+}
+
+/// The structural subfields of an `Ogrn`.
+pub struct OgrnInfo {
+    pub sign: u32,
+    pub year: u32,
+    pub region_code: u32,
+    pub inspection_code: u32,
+    pub record_number: u32,
+    pub check_digit: u32,
+}
+
+/// This structure describes the primary state registration number
+/// of an individual entrepreneur (ОГРНИП) and allows to obtain
+/// information about its properties. To check whether it is correct.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let ogrnip: Ogrnip = "304500116000157".to_owned().into();
+/// assert!(ogrnip.is_valid().unwrap());
+///
+/// ```
+///
+pub struct Ogrnip {
+    value: String,
+}
+
+impl Ogrnip {
+    const LENGTH: usize = 15;
+
+    /// Creates a new `Ogrnip`
+    pub fn new(input: &str) -> Self {
+        Ogrnip {
+            value: input.into(),
+        }
+    }
+
+    /// Calculates the check digit by taking the first 14 digits
+    /// as an integer modulo 13, and the last decimal digit of that
+    /// remainder.
+    fn check_digit(&self) -> u32 {
+        let mut acc: u64 = 0;
+
+        for i in 0..14 {
+            let digit = get_digit(&self.value, i) as u64;
+            acc = (acc * 10 + digit) % 13;
+        }
+
+        (acc % 10) as u32
+    }
+}
+
+impl Validate for Ogrn {
+    fn is_valid(&self) -> ValidResult {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Ogrn::LENGTH {
+            return Err(Error::WrongLength {
+                length: Ogrn::LENGTH,
+            });
+        }
+
+        Ok(self.check_digit() == get_digit(&self.value, 12))
+    }
+}
+
+impl From<String> for Ogrn {
+    fn from(other: String) -> Ogrn {
+        Ogrn { value: other }
+    }
+}
+
+impl Validate for Ogrnip {
+    fn is_valid(&self) -> ValidResult {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Ogrnip::LENGTH {
+            return Err(Error::WrongLength {
+                length: Ogrnip::LENGTH,
+            });
+        }
+
+        Ok(self.check_digit() == get_digit(&self.value, 14))
+    }
+}
+
+impl From<String> for Ogrnip {
+    fn from(other: String) -> Ogrnip {
+        Ogrnip { value: other }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error;
+    use super::*;
+
+    fn create_ogrn(s: &str) -> Ogrn {
+        Ogrn::new(s)
+    }
+
+    fn create_ogrnip(s: &str) -> Ogrnip {
+        Ogrnip::new(s)
+    }
+
+    #[test]
+    fn test_empty_ogrn() {
+        assert!(match create_ogrn("").is_valid() {
+            Err(error::Error::Empty) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_invalid_ogrn_wrong_length() {
+        match create_ogrn("102770013").is_valid() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_valid_ogrn() {
+        assert!(create_ogrn("1027700132195").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_check_digit_ogrn() {
+        assert!(create_ogrn("1027700132196").is_valid().unwrap() == false);
+    }
+
+    #[test]
+    fn test_invalid_ogrn_with_litters() {
+        match create_ogrn("102770013219f").is_valid() {
+            Err(error::Error::ExpectedNumbersOnly) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_decode_ogrn() {
+        let info = create_ogrn("1027700132195").decode().unwrap();
+        assert_eq!(info.sign, 1);
+        assert_eq!(info.year, 2);
+        assert_eq!(info.region_code, 77);
+        assert_eq!(info.inspection_code, 0);
+        assert_eq!(info.record_number, 13219);
+        assert_eq!(info.check_digit, 5);
+    }
+
+    #[test]
+    fn test_decode_ogrn_wrong_length() {
+        match create_ogrn("102770013").decode() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_empty_ogrnip() {
+        assert!(match create_ogrnip("").is_valid() {
+            Err(error::Error::Empty) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_invalid_ogrnip_wrong_length() {
+        match create_ogrnip("304500116").is_valid() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_valid_ogrnip() {
+        assert!(create_ogrnip("304500116000157").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_check_digit_ogrnip() {
+        assert!(create_ogrnip("304500116000158").is_valid().unwrap() == false);
+    }
+}
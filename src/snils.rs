@@ -0,0 +1,137 @@
+use super::{ValidResult, Validate};
+use digits::get_digit;
+use error::Error;
+
+/// This structure describes the individual insurance account number
+/// (СНИЛС) and allows to obtain information about its properties.
+/// To check whether it is correct.
+///
+/// # Examples
+///
+/// ```rust
+///
+/// use government_id::*;
+/// let snils: Snils = "11223344595".to_owned().into();
+/// assert!(snils.is_valid().unwrap());
+///
+/// ```
+///
+pub struct Snils {
+    value: String,
+}
+
+impl Snils {
+    const LENGTH: usize = 11;
+
+    /// Creates a new `Snils`
+    pub fn new(input: &str) -> Self {
+        Snils {
+            value: input.into(),
+        }
+    }
+
+    /// Calculates the control number from the first 9 digits.
+    fn check_digit(&self) -> u32 {
+        let mut sum = 0;
+
+        for i in 0..9 {
+            let num = get_digit(&self.value, i);
+            sum += num * (9 - i as u32);
+        }
+
+        match sum {
+            0...99 => sum,
+            100 | 101 => 0,
+            _ => match sum % 101 {
+                100 | 101 => 0,
+                rest => rest,
+            },
+        }
+    }
+}
+
+impl Validate for Snils {
+    fn is_valid(&self) -> ValidResult {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        if self.value.len() != Snils::LENGTH {
+            return Err(Error::WrongLength {
+                length: Snils::LENGTH,
+            });
+        }
+
+        let control = self.check_digit();
+
+        Ok(control == get_digit(&self.value, 9) * 10 + get_digit(&self.value, 10))
+    }
+}
+
+impl From<String> for Snils {
+    fn from(other: String) -> Snils {
+        Snils { value: other }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error;
+    use super::*;
+
+    fn create_snils(s: &str) -> Snils {
+        Snils::new(s)
+    }
+
+    #[test]
+    fn test_empty_snils() {
+        assert!(match create_snils("").is_valid() {
+            Err(error::Error::Empty) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_invalid_snils_wrong_length() {
+        match create_snils("1122334").is_valid() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_valid_snils_all_zeros() {
+        assert!(create_snils("00000000000").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_valid_snils() {
+        assert!(create_snils("11223344595").is_valid().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_check_digit_snils() {
+        assert!(create_snils("11223344594").is_valid().unwrap() == false);
+    }
+
+    #[test]
+    fn test_invalid_snils_with_litters() {
+        match create_snils("1122334459f").is_valid() {
+            Err(error::Error::ExpectedNumbersOnly) => assert!(true),
+            _ => assert!(false),
+        };
+    }
+
+    #[test]
+    fn test_convert_from_string() {
+        let snils: Snils = "11223344595".to_owned().into();
+        assert!(match snils.is_valid() {
+            Ok(true) => true,
+            _ => false,
+        })
+    }
+}
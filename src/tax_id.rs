@@ -1,4 +1,10 @@
+//! `decode()` on the types in this crate only checks that a value is
+//! structurally valid (right length, digits only) before slicing out
+//! its subfields — it does not verify the checksum, so a
+//! structurally decoded value may still fail `is_valid()`.
+
 use super::{ValidResult, Validate};
+use digits::{digits_to_u32, get_digit};
 use error::Error;
 
 /// This structure describes taxpayer identification number
@@ -28,6 +34,12 @@ impl TaxpayerIdentificationNumber {
         }
     }
 
+    /// Returns the raw value this identifier was constructed from.
+    #[cfg(feature = "fns_online")]
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+
     /// Verifies the tax ID of the individual entrepreneur.
     fn check_len12(&self) -> bool {
         let calc_num1 = self.check_digit(&TaxpayerIdentificationNumber::RATIO[1..]);
@@ -53,19 +65,64 @@ impl TaxpayerIdentificationNumber {
         }
         sum % 11 % 10
     }
-}
 
-/// Gets number from string by index.
-fn get_digit(input: &str, n: usize) -> u32 {
-    match input.chars().nth(n) {
-        Some(ch) => match ch.to_digit(10) {
-            Some(d) => d,
-            None => 0,
-        },
-        None => 0,
+    /// Decodes the number into its structural subfields: the region
+    /// code, the tax-inspection code, the serial portion and the
+    /// check digit(s). This is a structural decode only — see the
+    /// module-level note on what "structurally valid" means here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    ///
+    /// use government_id::*;
+    /// let tin = TaxpayerIdentificationNumber::new("7827004526");
+    /// let info = tin.decode().unwrap();
+    /// assert_eq!(info.region_code, 78);
+    ///
+    /// ```
+    ///
+    pub fn decode(&self) -> Result<TaxpayerIdentificationNumberInfo, Error> {
+        if self.value.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if !super::only_digits(&self.value) {
+            return Err(Error::ExpectedNumbersOnly);
+        }
+
+        let region_code = digits_to_u32(&self.value, 0, 2);
+        let inspection_code = digits_to_u32(&self.value, 2, 2);
+
+        match self.value.len() {
+            10 => Ok(TaxpayerIdentificationNumberInfo {
+                region_code,
+                inspection_code,
+                serial: digits_to_u32(&self.value, 4, 5),
+                check_digit: get_digit(&self.value, 9),
+                check_digit2: None,
+            }),
+            12 => Ok(TaxpayerIdentificationNumberInfo {
+                region_code,
+                inspection_code,
+                serial: digits_to_u32(&self.value, 4, 6),
+                check_digit: get_digit(&self.value, 10),
+                check_digit2: Some(get_digit(&self.value, 11)),
+            }),
+            _ => Err(Error::WrongLength { length: 12 }),
+        }
     }
 }
 
+/// The structural subfields of a `TaxpayerIdentificationNumber`.
+pub struct TaxpayerIdentificationNumberInfo {
+    pub region_code: u32,
+    pub inspection_code: u32,
+    pub serial: u32,
+    pub check_digit: u32,
+    pub check_digit2: Option<u32>,
+}
+
 impl Validate for TaxpayerIdentificationNumber {
     fn is_valid(&self) -> ValidResult {
         if self.value.is_empty() {
@@ -203,4 +260,35 @@ mod tests {
             _ => false,
         })
     }
+
+    #[test]
+    fn test_decode_taxpayer_identification_number_10_numbers() {
+        let info = create_taxpayer_identification_number("7827004526")
+            .decode()
+            .unwrap();
+        assert_eq!(info.region_code, 78);
+        assert_eq!(info.inspection_code, 27);
+        assert_eq!(info.serial, 452);
+        assert_eq!(info.check_digit, 6);
+        assert_eq!(info.check_digit2, None);
+    }
+
+    #[test]
+    fn test_decode_taxpayer_identification_number_12_numbers() {
+        let info = create_taxpayer_identification_number("760307073214")
+            .decode()
+            .unwrap();
+        assert_eq!(info.region_code, 76);
+        assert_eq!(info.inspection_code, 03);
+        assert_eq!(info.check_digit, 1);
+        assert_eq!(info.check_digit2, Some(4));
+    }
+
+    #[test]
+    fn test_decode_invalid_length() {
+        match create_taxpayer_identification_number("772053").decode() {
+            Err(error::Error::WrongLength { length: _ }) => assert!(true),
+            _ => assert!(false),
+        };
+    }
 }
@@ -0,0 +1,142 @@
+//! Online verification against the Russian Federal Tax Service (FNS)
+//! registry. This confirms that an identifier is actually registered,
+//! as opposed to `is_valid()` which only checks the checksum. Gated
+//! behind the `fns_online` Cargo feature so the core crate stays
+//! dependency-free by default.
+
+#[cfg(feature = "fns_online")]
+use super::Validate;
+#[cfg(feature = "fns_online")]
+use error::Error;
+#[cfg(feature = "fns_online")]
+use ogrn::Ogrn;
+#[cfg(feature = "fns_online")]
+use tax_id::TaxpayerIdentificationNumber;
+
+/// An identifier that has already passed its own checksum and is
+/// therefore safe to hand to a `Verifier`. Constructing one checks
+/// `is_valid()` up front, so the precondition a `Verifier` relies on
+/// is enforced by the type rather than left to a doc comment.
+#[cfg(feature = "fns_online")]
+pub enum VerifiableId {
+    Inn(TaxpayerIdentificationNumber),
+    Ogrn(Ogrn),
+}
+
+#[cfg(feature = "fns_online")]
+impl VerifiableId {
+    /// Wraps an INN for verification, failing if it hasn't passed its
+    /// own checksum.
+    pub fn from_inn(id: TaxpayerIdentificationNumber) -> Result<Self, Error> {
+        match id.is_valid() {
+            Ok(true) => Ok(VerifiableId::Inn(id)),
+            Ok(false) => Err(Error::InvalidChecksum),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Wraps an OGRN for verification, failing if it hasn't passed
+    /// its own checksum.
+    pub fn from_ogrn(id: Ogrn) -> Result<Self, Error> {
+        match id.is_valid() {
+            Ok(true) => Ok(VerifiableId::Ogrn(id)),
+            Ok(false) => Err(Error::InvalidChecksum),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match *self {
+            VerifiableId::Inn(ref id) => id.value(),
+            VerifiableId::Ogrn(ref id) => id.value(),
+        }
+    }
+}
+
+/// The registration status of an identifier as reported by the FNS
+/// registry.
+#[cfg(feature = "fns_online")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistrationStatus {
+    Registered,
+    Liquidated,
+}
+
+/// The result of an online verification against the FNS registry.
+#[cfg(feature = "fns_online")]
+#[derive(Debug, Clone)]
+pub struct VerificationResult {
+    pub status: RegistrationStatus,
+    pub organization_name: String,
+    pub registration_date: String,
+}
+
+/// Confirms that a locally-valid identifier is actually registered
+/// with the Federal Tax Service. Taking a `VerifiableId` rather than
+/// a raw string means the checksum precondition is enforced when the
+/// `VerifiableId` is constructed, not merely documented here.
+#[cfg(feature = "fns_online")]
+pub trait Verifier {
+    /// Looks up `id` in the FNS registry.
+    fn verify(&self, id: &VerifiableId) -> Result<VerificationResult, Error>;
+}
+
+/// A `Verifier` backed by the public FNS online service at
+/// `service.nalog.ru`.
+#[cfg(feature = "fns_online")]
+pub struct FnsOnlineVerifier {
+    endpoint: String,
+}
+
+#[cfg(feature = "fns_online")]
+impl FnsOnlineVerifier {
+    const DEFAULT_ENDPOINT: &'static str = "https://service.nalog.ru/zd.do";
+
+    /// Creates a verifier pointed at the default FNS endpoint.
+    pub fn new() -> Self {
+        FnsOnlineVerifier {
+            endpoint: FnsOnlineVerifier::DEFAULT_ENDPOINT.into(),
+        }
+    }
+
+    /// Creates a verifier pointed at a custom endpoint, useful for
+    /// pointing tests at a mock server.
+    pub fn with_endpoint(endpoint: &str) -> Self {
+        FnsOnlineVerifier {
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+#[cfg(feature = "fns_online")]
+impl Default for FnsOnlineVerifier {
+    fn default() -> Self {
+        FnsOnlineVerifier::new()
+    }
+}
+
+#[cfg(feature = "fns_online")]
+impl Verifier for FnsOnlineVerifier {
+    fn verify(&self, id: &VerifiableId) -> Result<VerificationResult, Error> {
+        let url = format!("{}?id={}", self.endpoint, id.as_str());
+
+        let mut response = reqwest::get(&url).map_err(|_| Error::NetworkFailure)?;
+
+        let body: serde_json::Value = response.json().map_err(|_| Error::ResponseParseFailure)?;
+
+        let liquidated = body["liquidated"].as_bool().unwrap_or(false);
+
+        Ok(VerificationResult {
+            status: if liquidated {
+                RegistrationStatus::Liquidated
+            } else {
+                RegistrationStatus::Registered
+            },
+            organization_name: body["name"].as_str().unwrap_or_default().into(),
+            registration_date: body["registration_date"]
+                .as_str()
+                .unwrap_or_default()
+                .into(),
+        })
+    }
+}